@@ -0,0 +1,117 @@
+use rodio::{OutputStream, Sink, Source};
+use std::f64::consts::PI;
+use std::time::Duration;
+
+const SAMPLE_RATE: u32 = 44_100;
+const ENVELOPE_MS: f64 = 10.0;
+
+struct ToneSource {
+    samples: Vec<f32>,
+    position: usize,
+}
+
+impl ToneSource {
+    fn new(samples: Vec<f32>) -> ToneSource {
+        ToneSource {
+            samples,
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for ToneSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let sample = self.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+}
+
+impl Source for ToneSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+fn apply_envelope(samples: &mut [f32]) {
+    let fade_samples = ((SAMPLE_RATE as f64 * ENVELOPE_MS / 1000.0) as usize).min(samples.len() / 2);
+    for i in 0..fade_samples {
+        let gain = i as f32 / fade_samples as f32;
+        samples[i] *= gain;
+        let j = samples.len() - 1 - i;
+        samples[j] *= gain;
+    }
+}
+
+fn render(frequencies: &[f64], duration: Duration) -> Vec<f32> {
+    let sample_count = (SAMPLE_RATE as f64 * duration.as_secs_f64()) as usize;
+    let mut samples = vec![0.0f32; sample_count];
+
+    for &frequency in frequencies {
+        for (i, sample) in samples.iter_mut().enumerate() {
+            let t = i as f64 / SAMPLE_RATE as f64;
+            *sample += (2.0 * PI * frequency * t).sin() as f32;
+        }
+    }
+
+    if !frequencies.is_empty() {
+        let normalize = 1.0 / frequencies.len() as f32;
+        for sample in samples.iter_mut() {
+            *sample *= normalize;
+        }
+    }
+
+    apply_envelope(&mut samples);
+    samples
+}
+
+fn play(samples: Vec<f32>) {
+    let (_stream, stream_handle) = match OutputStream::try_default() {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+    let sink = match Sink::try_new(&stream_handle) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    sink.append(ToneSource::new(samples));
+    sink.sleep_until_end();
+}
+
+/// Plays each frequency one after another, as in a scale run.
+pub fn play_sequence(frequencies: &[f64]) {
+    let (_stream, stream_handle) = match OutputStream::try_default() {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+    let sink = match Sink::try_new(&stream_handle) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    for &frequency in frequencies {
+        sink.append(ToneSource::new(render(&[frequency], Duration::from_millis(400))));
+    }
+
+    sink.sleep_until_end();
+}
+
+/// Plays every frequency at once, summed into a single chord voicing.
+pub fn play_chord(frequencies: &[f64]) {
+    play(render(frequencies, Duration::from_millis(1200)));
+}
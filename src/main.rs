@@ -2,7 +2,27 @@ use console::Term;
 use rand::Rng;
 use std::io::{self, Write};
 
+mod audio;
+mod midi;
 mod music;
+mod stats;
+
+/// Renders a scale's notes in German pitch notation (B natural is "H")
+/// and its scale-degree Roman numerals, for display alongside the
+/// English spelling already shown in the quiz prompt.
+fn scale_notation_lines(scale: &music::Scale) -> String {
+    let german: Vec<String> = scale
+        .notes()
+        .iter()
+        .map(|n| n.string_in(music::Notation::German, None))
+        .collect();
+    format!(
+        "German: {}\n\
+        Degrees: {}",
+        german.join(" "),
+        scale.roman_numerals().join(" "),
+    )
+}
 
 fn coming_soon() -> Page<PageState> {
     Page {
@@ -23,7 +43,11 @@ fn main_page() -> Page<PageState> {
         match key {
             console::Key::Char('1') => Action::Render(interval_quiz()),
             console::Key::Char('2') => Action::Render(coming_soon()),
-            console::Key::Char('3') => Action::Render(coming_soon()),
+            console::Key::Char('3') => Action::Render(chord_quiz()),
+            console::Key::Char('4') => Action::Render(identify_quiz()),
+            console::Key::Char('5') => Action::Render(ear_training_quiz()),
+            console::Key::Char('6') => Action::Render(diatonic_chord_quiz()),
+            console::Key::Char('7') => Action::Render(exotic_scale_quiz()),
             console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
             _ => Action::Noop,
         }
@@ -38,6 +62,10 @@ fn main_page() -> Page<PageState> {
         [1] Scale Types\n\
         [2] Intervals\n\
         [3] Chords\n\
+        [4] Name That Chord\n\
+        [5] Ear Training\n\
+        [6] Diatonic Chords\n\
+        [7] Exotic Scales\n\
         \n\
         [q] Quit",
         ),
@@ -47,16 +75,26 @@ fn main_page() -> Page<PageState> {
 }
 
 fn interval_quiz() -> Page<PageState> {
-    let rng = rand::thread_rng();
+    let mut rng = rand::thread_rng();
     let circle_of_fifths = music::circle_of_fifths();
+    let session_stats = stats::Stats::load();
 
-    fn random_scale(mut rng: rand::rngs::ThreadRng, notes: Vec<music::Note>) -> music::Scale {
+    fn random_scale(
+        rng: &mut rand::rngs::ThreadRng,
+        notes: &[music::Note],
+        session_stats: &stats::Stats,
+    ) -> music::Scale {
         let random_tonic = notes.get(rng.gen_range(0..notes.len())).unwrap();
-        let random_type: music::ScaleType = rng.gen();
+        let types = music::ScaleType::all();
+        let weights: Vec<f64> = types
+            .iter()
+            .map(|t| session_stats.weight(&format!("scale:{}", t.as_ref())))
+            .collect();
+        let random_type = types[stats::weighted_index(rng, &weights)];
         music::Scale::new(random_tonic, random_type)
     }
 
-    let scale = random_scale(rng, circle_of_fifths);
+    let scale = random_scale(&mut rng, &circle_of_fifths, &session_stats);
 
     let choices: Vec<String> = music::ScaleType::all()
         .iter()
@@ -66,7 +104,7 @@ fn interval_quiz() -> Page<PageState> {
 
     let correct_choice = music::ScaleType::all()
         .iter()
-        .position(|t| *t == scale.scale_type())
+        .position(|t| Some(*t) == scale.scale_type())
         .unwrap();
 
     let text: String = format!(
@@ -76,6 +114,7 @@ fn interval_quiz() -> Page<PageState> {
         \n\
         {}\n\
         \n\
+        [e] Export MIDI\n\
         [m] Main Menu\n\
         [q] Quit",
         scale.string(),
@@ -86,21 +125,35 @@ fn interval_quiz() -> Page<PageState> {
         match key {
             console::Key::Char('m') | console::Key::Char('M') => Action::Render(main_page()),
             console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
+            console::Key::Char('e') | console::Key::Char('E') => {
+                let filename = format!(
+                    "{}_{}.mid",
+                    state.scale.notes()[0].string(),
+                    state.scale.scale_type().unwrap().as_ref()
+                );
+                export_midi(filename, state.scale.to_midi())
+            }
             console::Key::Char(c) => {
-                if c >= '0' || c <= '9' {
+                if c >= '0' && c <= '9' {
                     let choice = c.to_digit(10).unwrap();
                     let scale = state.scale.clone();
+                    let key = format!("scale:{}", scale.scale_type().unwrap().as_ref());
+                    let mut session_stats = stats::Stats::load();
 
                     if choice as usize == state.correct_choice {
+                        session_stats.record_correct(&key);
+                        session_stats.save();
                         Action::Render(Page {
                             text: format!(
                                 "✅ That is correct\n\
                                 \n\
                                 {} is a {} scale\n\
+                                {}\n\
                                 \n\
                                 Press any key to continue",
                                 scale.string(),
-                                scale.scale_type().as_ref(),
+                                scale.scale_type().unwrap().as_ref(),
+                                scale_notation_lines(&scale),
                             ),
                             handler: |k, s| -> Action<PageState> {
                                 Action::Render(interval_quiz())
@@ -108,15 +161,19 @@ fn interval_quiz() -> Page<PageState> {
                             state: state.clone(),
                         })
                     } else {
+                        session_stats.record_incorrect(&key);
+                        session_stats.save();
                         Action::Render(Page {
                             text: format!(
                                 "❌ That's not correct\n\
                                 \n\
                                 {} is a {} scale\n\
+                                {}\n\
                                 \n\
                                 Press any key to continue",
                                 scale.string(),
-                                scale.scale_type().as_ref(),
+                                scale.scale_type().unwrap().as_ref(),
+                                scale_notation_lines(&scale),
                             ),
                             handler: |k, s| -> Action<PageState> {
                                 Action::Render(interval_quiz())
@@ -138,10 +195,680 @@ fn interval_quiz() -> Page<PageState> {
         state: PageState {
             correct_choice: correct_choice + 1, // user enters 1-indexed values
             scale,
+            chord: PageState::empty().chord,
         },
     }
 }
 
+fn chord_quiz() -> Page<PageState> {
+    let mut rng = rand::thread_rng();
+    let circle_of_fifths = music::circle_of_fifths();
+    let session_stats = stats::Stats::load();
+
+    fn random_chord(
+        rng: &mut rand::rngs::ThreadRng,
+        notes: &[music::Note],
+        session_stats: &stats::Stats,
+    ) -> music::Chord {
+        let random_root = notes.get(rng.gen_range(0..notes.len())).unwrap();
+        let types = music::ChordType::all();
+        let weights: Vec<f64> = types
+            .iter()
+            .map(|t| session_stats.weight(&format!("chord:{}", t.as_ref())))
+            .collect();
+        let random_type = types[stats::weighted_index(rng, &weights)];
+        music::Chord::new(random_root, random_type)
+    }
+
+    let chord = random_chord(&mut rng, &circle_of_fifths, &session_stats);
+
+    let choices: Vec<String> = music::ChordType::all()
+        .iter()
+        .enumerate()
+        .map(|x| format!("[{}] {}", x.0 + 1, (*x.1).as_ref()))
+        .collect();
+
+    let correct_choice = music::ChordType::all()
+        .iter()
+        .position(|t| *t == chord.chord_type())
+        .unwrap();
+
+    let text: String = format!(
+        "What kind of chord is this?\n\
+        \n\
+        {}\n\
+        \n\
+        {}\n\
+        \n\
+        [e] Export MIDI\n\
+        [m] Main Menu\n\
+        [q] Quit",
+        chord.string(),
+        choices.join("\n"),
+    );
+
+    fn handler(key: console::Key, state: PageState) -> Action<PageState> {
+        match key {
+            console::Key::Char('m') | console::Key::Char('M') => Action::Render(main_page()),
+            console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
+            console::Key::Char('e') | console::Key::Char('E') => {
+                let filename = format!(
+                    "{}_{}.mid",
+                    state.chord.notes()[0].string(),
+                    state.chord.chord_type().as_ref()
+                );
+                export_midi(filename, state.chord.to_midi())
+            }
+            console::Key::Char(c) => {
+                if c >= '0' && c <= '9' {
+                    let choice = c.to_digit(10).unwrap();
+                    let chord = state.chord.clone();
+                    let key = format!("chord:{}", chord.chord_type().as_ref());
+                    let mut session_stats = stats::Stats::load();
+
+                    if choice as usize == state.correct_choice {
+                        session_stats.record_correct(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "✅ That is correct\n\
+                                \n\
+                                {} is a {} chord\n\
+                                \n\
+                                Press any key to continue",
+                                chord.string(),
+                                chord.chord_type().as_ref(),
+                            ),
+                            handler: |k, s| -> Action<PageState> { Action::Render(chord_quiz()) },
+                            state: state.clone(),
+                        })
+                    } else {
+                        session_stats.record_incorrect(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "❌ That's not correct\n\
+                                \n\
+                                {} is a {} chord\n\
+                                \n\
+                                Press any key to continue",
+                                chord.string(),
+                                chord.chord_type().as_ref(),
+                            ),
+                            handler: |k, s| -> Action<PageState> { Action::Render(chord_quiz()) },
+                            state: state.clone(),
+                        })
+                    }
+                } else {
+                    Action::Noop
+                }
+            }
+            _ => Action::Noop,
+        }
+    }
+
+    Page {
+        text,
+        handler: handler,
+        state: PageState {
+            correct_choice: correct_choice + 1, // user enters 1-indexed values
+            scale: PageState::empty().scale,
+            chord,
+        },
+    }
+}
+
+fn identify_quiz() -> Page<PageState> {
+    let mut rng = rand::thread_rng();
+    let circle_of_fifths = music::circle_of_fifths();
+    let session_stats = stats::Stats::load();
+
+    fn random_chord(
+        rng: &mut rand::rngs::ThreadRng,
+        notes: &[music::Note],
+        session_stats: &stats::Stats,
+    ) -> music::Chord {
+        let random_root = notes.get(rng.gen_range(0..notes.len())).unwrap();
+        let types = music::ChordType::all();
+        let weights: Vec<f64> = types
+            .iter()
+            .map(|t| session_stats.weight(&format!("chord:{}", t.as_ref())))
+            .collect();
+        let random_type = types[stats::weighted_index(rng, &weights)];
+        music::Chord::new(random_root, random_type)
+    }
+
+    let chord = random_chord(&mut rng, &circle_of_fifths, &session_stats);
+    let matches = music::identify(chord.notes());
+
+    let choices: Vec<String> = music::ChordType::all()
+        .iter()
+        .enumerate()
+        .map(|x| format!("[{}] {}", x.0 + 1, (*x.1).as_ref()))
+        .collect();
+
+    let correct_choice = music::ChordType::all()
+        .iter()
+        .position(|t| matches.iter().any(|m| m.label.ends_with((*t).as_ref())))
+        .unwrap();
+
+    let notes: Vec<String> = chord.notes().iter().map(|n| n.string()).collect();
+
+    let text: String = format!(
+        "These notes form a chord. What is it?\n\
+        \n\
+        {}\n\
+        \n\
+        {}\n\
+        \n\
+        [e] Export MIDI\n\
+        [m] Main Menu\n\
+        [q] Quit",
+        notes.join(" "),
+        choices.join("\n"),
+    );
+
+    fn handler(key: console::Key, state: PageState) -> Action<PageState> {
+        match key {
+            console::Key::Char('m') | console::Key::Char('M') => Action::Render(main_page()),
+            console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
+            console::Key::Char('e') | console::Key::Char('E') => {
+                let filename = format!(
+                    "{}_{}.mid",
+                    state.chord.notes()[0].string(),
+                    state.chord.chord_type().as_ref()
+                );
+                export_midi(filename, state.chord.to_midi())
+            }
+            console::Key::Char(c) => {
+                if c >= '0' && c <= '9' {
+                    let choice = c.to_digit(10).unwrap();
+                    let chord = state.chord.clone();
+                    let notes: Vec<String> = chord.notes().iter().map(|n| n.string()).collect();
+                    let analysis = music::identify_string(chord.notes());
+                    let key = format!("chord:{}", chord.chord_type().as_ref());
+                    let mut session_stats = stats::Stats::load();
+
+                    if choice as usize == state.correct_choice {
+                        session_stats.record_correct(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "✅ That is correct\n\
+                                \n\
+                                {}\n\
+                                \n\
+                                {}\n\
+                                \n\
+                                Press any key to continue",
+                                notes.join(" "),
+                                analysis,
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(identify_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    } else {
+                        session_stats.record_incorrect(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "❌ That's not correct\n\
+                                \n\
+                                {}\n\
+                                \n\
+                                {}\n\
+                                \n\
+                                Press any key to continue",
+                                notes.join(" "),
+                                analysis,
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(identify_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    }
+                } else {
+                    Action::Noop
+                }
+            }
+            _ => Action::Noop,
+        }
+    }
+
+    Page {
+        text,
+        handler: handler,
+        state: PageState {
+            correct_choice: correct_choice + 1, // user enters 1-indexed values
+            scale: PageState::empty().scale,
+            chord,
+        },
+    }
+}
+
+fn ear_training_quiz() -> Page<PageState> {
+    let mut rng = rand::thread_rng();
+    let circle_of_fifths = music::circle_of_fifths();
+    let session_stats = stats::Stats::load();
+
+    fn random_scale(
+        rng: &mut rand::rngs::ThreadRng,
+        notes: &[music::Note],
+        session_stats: &stats::Stats,
+    ) -> music::Scale {
+        let random_tonic = notes.get(rng.gen_range(0..notes.len())).unwrap();
+        let types = music::ScaleType::all();
+        let weights: Vec<f64> = types
+            .iter()
+            .map(|t| session_stats.weight(&format!("scale:{}", t.as_ref())))
+            .collect();
+        let random_type = types[stats::weighted_index(rng, &weights)];
+        music::Scale::new(random_tonic, random_type)
+    }
+
+    let scale = random_scale(&mut rng, &circle_of_fifths, &session_stats);
+    scale.play();
+
+    let choices: Vec<String> = music::ScaleType::all()
+        .iter()
+        .enumerate()
+        .map(|x| format!("[{}] {}", x.0 + 1, (*x.1).as_ref()))
+        .collect();
+
+    let correct_choice = music::ScaleType::all()
+        .iter()
+        .position(|t| Some(*t) == scale.scale_type())
+        .unwrap();
+
+    let text: String = format!(
+        "Listen closely. What kind of scale did you just hear?\n\
+        \n\
+        {}\n\
+        \n\
+        [p] Play it again\n\
+        [e] Export MIDI\n\
+        [m] Main Menu\n\
+        [q] Quit",
+        choices.join("\n"),
+    );
+
+    fn handler(key: console::Key, state: PageState) -> Action<PageState> {
+        match key {
+            console::Key::Char('p') | console::Key::Char('P') => {
+                state.scale.play();
+                Action::Noop
+            }
+            console::Key::Char('m') | console::Key::Char('M') => Action::Render(main_page()),
+            console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
+            console::Key::Char('e') | console::Key::Char('E') => {
+                let filename = format!(
+                    "{}_{}.mid",
+                    state.scale.notes()[0].string(),
+                    state.scale.scale_type().unwrap().as_ref()
+                );
+                export_midi(filename, state.scale.to_midi())
+            }
+            console::Key::Char(c) => {
+                if c >= '0' && c <= '9' {
+                    let choice = c.to_digit(10).unwrap();
+                    let scale = state.scale.clone();
+                    let key = format!("scale:{}", scale.scale_type().unwrap().as_ref());
+                    let mut session_stats = stats::Stats::load();
+
+                    if choice as usize == state.correct_choice {
+                        session_stats.record_correct(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "✅ That is correct\n\
+                                \n\
+                                {} is a {} scale\n\
+                                {}\n\
+                                \n\
+                                Press any key to continue",
+                                scale.string(),
+                                scale.scale_type().unwrap().as_ref(),
+                                scale_notation_lines(&scale),
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(ear_training_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    } else {
+                        session_stats.record_incorrect(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "❌ That's not correct\n\
+                                \n\
+                                {} is a {} scale\n\
+                                {}\n\
+                                \n\
+                                Press any key to continue",
+                                scale.string(),
+                                scale.scale_type().unwrap().as_ref(),
+                                scale_notation_lines(&scale),
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(ear_training_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    }
+                } else {
+                    Action::Noop
+                }
+            }
+            _ => Action::Noop,
+        }
+    }
+
+    Page {
+        text,
+        handler: handler,
+        state: PageState {
+            correct_choice: correct_choice + 1, // user enters 1-indexed values
+            scale,
+            chord: PageState::empty().chord,
+        },
+    }
+}
+
+fn diatonic_chord_quiz() -> Page<PageState> {
+    let mut rng = rand::thread_rng();
+    let circle_of_fifths = music::circle_of_fifths();
+    let session_stats = stats::Stats::load();
+
+    fn random_scale(
+        rng: &mut rand::rngs::ThreadRng,
+        notes: &[music::Note],
+        session_stats: &stats::Stats,
+    ) -> music::Scale {
+        let random_tonic = notes.get(rng.gen_range(0..notes.len())).unwrap();
+        let types = music::ScaleType::all();
+        let weights: Vec<f64> = types
+            .iter()
+            .map(|t| session_stats.weight(&format!("scale:{}", t.as_ref())))
+            .collect();
+        let random_type = types[stats::weighted_index(rng, &weights)];
+        music::Scale::new(random_tonic, random_type)
+    }
+
+    let scale = random_scale(&mut rng, &circle_of_fifths, &session_stats);
+    let tonic = *scale.notes().get(0).unwrap();
+    let diatonic_chords = scale.diatonic_chords(3);
+    let degree = rng.gen_range(0..diatonic_chords.len());
+    let diatonic_chord = &diatonic_chords[degree];
+    let chord_type = diatonic_chord.chord_type.unwrap_or(music::ChordType::Major);
+    let chord = music::Chord::new(&diatonic_chord.root, chord_type);
+
+    let choices: Vec<String> = music::ChordType::all()
+        .iter()
+        .enumerate()
+        .map(|x| format!("[{}] {}", x.0 + 1, (*x.1).as_ref()))
+        .collect();
+
+    let correct_choice = music::ChordType::all()
+        .iter()
+        .position(|t| *t == chord_type)
+        .unwrap();
+
+    let numerals = diatonic_chords
+        .iter()
+        .map(|c| c.roman_label(&tonic))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let nashville = diatonic_chords
+        .iter()
+        .map(|c| c.root.string_in(music::Notation::Nashville, Some(&tonic)))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let text: String = format!(
+        "In the key of {} {}: {} (Nashville {})\n\
+        \n\
+        What kind of chord is {} ({})?\n\
+        \n\
+        {}\n\
+        \n\
+        [e] Export MIDI\n\
+        [m] Main Menu\n\
+        [q] Quit",
+        tonic.string(),
+        scale.scale_type().unwrap().as_ref(),
+        numerals,
+        nashville,
+        diatonic_chord.roman_label(&tonic),
+        diatonic_chord.string(),
+        choices.join("\n"),
+    );
+
+    fn handler(key: console::Key, state: PageState) -> Action<PageState> {
+        match key {
+            console::Key::Char('m') | console::Key::Char('M') => Action::Render(main_page()),
+            console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
+            console::Key::Char('e') | console::Key::Char('E') => {
+                let filename = format!(
+                    "{}_{}.mid",
+                    state.chord.notes()[0].string(),
+                    state.chord.chord_type().as_ref()
+                );
+                export_midi(filename, state.chord.to_midi())
+            }
+            console::Key::Char(c) => {
+                if c >= '0' && c <= '9' {
+                    let choice = c.to_digit(10).unwrap();
+                    let chord = state.chord.clone();
+                    let key = format!("chord:{}", chord.chord_type().as_ref());
+                    let mut session_stats = stats::Stats::load();
+
+                    if choice as usize == state.correct_choice {
+                        session_stats.record_correct(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "✅ That is correct\n\
+                                \n\
+                                {} is a {} chord\n\
+                                \n\
+                                Press any key to continue",
+                                chord.string(),
+                                chord.chord_type().as_ref(),
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(diatonic_chord_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    } else {
+                        session_stats.record_incorrect(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "❌ That's not correct\n\
+                                \n\
+                                {} is a {} chord\n\
+                                \n\
+                                Press any key to continue",
+                                chord.string(),
+                                chord.chord_type().as_ref(),
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(diatonic_chord_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    }
+                } else {
+                    Action::Noop
+                }
+            }
+            _ => Action::Noop,
+        }
+    }
+
+    Page {
+        text,
+        handler: handler,
+        state: PageState {
+            correct_choice: correct_choice + 1, // user enters 1-indexed values
+            scale,
+            chord,
+        },
+    }
+}
+
+struct ExoticScale {
+    name: &'static str,
+    steps: &'static str,
+}
+
+/// Step patterns that don't fit `ScaleType`'s fixed enum, built via
+/// `Scale::from_steps` instead (W/H/A = whole/half/augmented-second step).
+const EXOTIC_SCALES: [ExoticScale; 3] = [
+    ExoticScale {
+        name: "Harmonic Minor",
+        steps: "WHWWHAH",
+    },
+    ExoticScale {
+        name: "Whole Tone",
+        steps: "WWWWWW",
+    },
+    ExoticScale {
+        name: "Major Pentatonic",
+        steps: "WWAWA",
+    },
+];
+
+fn exotic_scale_quiz() -> Page<PageState> {
+    let mut rng = rand::thread_rng();
+    let circle_of_fifths = music::circle_of_fifths();
+    let session_stats = stats::Stats::load();
+
+    let weights: Vec<f64> = EXOTIC_SCALES
+        .iter()
+        .map(|s| session_stats.weight(&format!("exotic:{}", s.name)))
+        .collect();
+    let pattern = &EXOTIC_SCALES[stats::weighted_index(&mut rng, &weights)];
+    let tonic = circle_of_fifths
+        .get(rng.gen_range(0..circle_of_fifths.len()))
+        .unwrap();
+    let scale = music::Scale::from_steps(tonic, pattern.steps);
+
+    let choices: Vec<String> = EXOTIC_SCALES
+        .iter()
+        .enumerate()
+        .map(|(i, s)| format!("[{}] {}", i + 1, s.name))
+        .collect();
+
+    let correct_choice = EXOTIC_SCALES
+        .iter()
+        .position(|s| s.name == pattern.name)
+        .unwrap();
+
+    let text: String = format!(
+        "What kind of scale is this?\n\
+        \n\
+        {}\n\
+        \n\
+        {}\n\
+        \n\
+        [e] Export MIDI\n\
+        [m] Main Menu\n\
+        [q] Quit",
+        scale.string(),
+        choices.join("\n"),
+    );
+
+    fn handler(key: console::Key, state: PageState) -> Action<PageState> {
+        match key {
+            console::Key::Char('m') | console::Key::Char('M') => Action::Render(main_page()),
+            console::Key::Char('q') | console::Key::Char('Q') => Action::Destroy,
+            console::Key::Char('e') | console::Key::Char('E') => {
+                let name = EXOTIC_SCALES[state.correct_choice - 1].name;
+                let filename = format!("{}_{}.mid", state.scale.notes()[0].string(), name);
+                export_midi(filename, state.scale.to_midi())
+            }
+            console::Key::Char(c) => {
+                if c >= '0' && c <= '9' {
+                    let choice = c.to_digit(10).unwrap();
+                    let scale = state.scale.clone();
+                    let name = EXOTIC_SCALES[state.correct_choice - 1].name;
+                    let key = format!("exotic:{}", name);
+                    let mut session_stats = stats::Stats::load();
+
+                    if choice as usize == state.correct_choice {
+                        session_stats.record_correct(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "✅ That is correct\n\
+                                \n\
+                                {} is a {} scale\n\
+                                {}\n\
+                                \n\
+                                Press any key to continue",
+                                scale.string(),
+                                name,
+                                scale_notation_lines(&scale),
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(exotic_scale_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    } else {
+                        session_stats.record_incorrect(&key);
+                        session_stats.save();
+                        Action::Render(Page {
+                            text: format!(
+                                "❌ That's not correct\n\
+                                \n\
+                                {} is a {} scale\n\
+                                {}\n\
+                                \n\
+                                Press any key to continue",
+                                scale.string(),
+                                name,
+                                scale_notation_lines(&scale),
+                            ),
+                            handler: |k, s| -> Action<PageState> {
+                                Action::Render(exotic_scale_quiz())
+                            },
+                            state: state.clone(),
+                        })
+                    }
+                } else {
+                    Action::Noop
+                }
+            }
+            _ => Action::Noop,
+        }
+    }
+
+    Page {
+        text,
+        handler: handler,
+        state: PageState {
+            correct_choice: correct_choice + 1, // user enters 1-indexed values
+            scale,
+            chord: PageState::empty().chord,
+        },
+    }
+}
+
+/// Writes MIDI bytes to `filename` in the working directory and returns to
+/// the current page; failures (e.g. a read-only directory) are ignored.
+fn export_midi(filename: String, bytes: Vec<u8>) -> Action<PageState> {
+    let _ = std::fs::write(filename, bytes);
+    Action::Noop
+}
+
 fn main() {
     let term = Term::buffered_stdout();
     let mut screen = QuizScreen::fullscreen(term);
@@ -161,6 +888,7 @@ enum Action<T> {
 struct PageState {
     correct_choice: usize,
     scale: music::Scale,
+    chord: music::Chord,
 }
 
 impl PageState {
@@ -168,6 +896,7 @@ impl PageState {
         PageState {
             correct_choice: 0,
             scale: music::Scale::new(&music::Note::parse("C"), music::ScaleType::Major),
+            chord: music::Chord::new(&music::Note::parse("C"), music::ChordType::Major),
         }
     }
 }
@@ -234,10 +963,18 @@ impl QuizScreen {
         self.term.clear_screen()?;
         self.border()?;
         self.write_page(text)?;
+        self.write_footer()?;
         self.term.flush()?;
         Ok(())
     }
 
+    fn write_footer(&mut self) -> io::Result<()> {
+        let session_stats = stats::Stats::load();
+        self.term.move_cursor_to(4, self.height - 2)?;
+        self.term.write(session_stats.footer().as_bytes())?;
+        Ok(())
+    }
+
     fn write_page(&mut self, page: &str) -> io::Result<()> {
         for (i, line) in page.split("\n").enumerate() {
             self.term.move_cursor_to(4, i + 2)?;
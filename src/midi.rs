@@ -0,0 +1,78 @@
+const TICKS_PER_QUARTER: u16 = 480;
+const MICROSECONDS_PER_QUARTER: u32 = 500_000; // 120 BPM
+const VELOCITY: u8 = 96;
+
+fn write_var_len(buf: &mut Vec<u8>, value: u32) {
+    let mut bytes = vec![(value & 0x7f) as u8];
+    let mut remainder = value >> 7;
+    while remainder > 0 {
+        bytes.push(((remainder & 0x7f) as u8) | 0x80);
+        remainder >>= 7;
+    }
+    bytes.reverse();
+    buf.extend_from_slice(&bytes);
+}
+
+fn note_on(track: &mut Vec<u8>, delta: u32, note: u8) {
+    write_var_len(track, delta);
+    track.extend_from_slice(&[0x90, note, VELOCITY]);
+}
+
+fn note_off(track: &mut Vec<u8>, delta: u32, note: u8) {
+    write_var_len(track, delta);
+    track.extend_from_slice(&[0x80, note, 0]);
+}
+
+fn tempo_event(track: &mut Vec<u8>) {
+    write_var_len(track, 0);
+    track.extend_from_slice(&[0xFF, 0x51, 0x03]);
+    track.extend_from_slice(&MICROSECONDS_PER_QUARTER.to_be_bytes()[1..]);
+}
+
+fn end_of_track(track: &mut Vec<u8>) {
+    write_var_len(track, 0);
+    track.extend_from_slice(&[0xFF, 0x2F, 0x00]);
+}
+
+/// Wraps a track's raw MIDI event bytes in an MThd + MTrk Standard MIDI
+/// File (format 0, single track, 480 ticks per quarter note).
+fn file(track: Vec<u8>) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(b"MThd");
+    out.extend_from_slice(&6u32.to_be_bytes());
+    out.extend_from_slice(&0u16.to_be_bytes()); // format 0
+    out.extend_from_slice(&1u16.to_be_bytes()); // one track
+    out.extend_from_slice(&TICKS_PER_QUARTER.to_be_bytes());
+    out.extend_from_slice(b"MTrk");
+    out.extend_from_slice(&(track.len() as u32).to_be_bytes());
+    out.extend_from_slice(&track);
+    out
+}
+
+/// Builds an SMF where each note sounds one after another for
+/// `duration_ticks`, as in a scale run.
+pub fn sequence(notes: &[u8], duration_ticks: u32) -> Vec<u8> {
+    let mut track = Vec::new();
+    tempo_event(&mut track);
+    for &note in notes {
+        note_on(&mut track, 0, note);
+        note_off(&mut track, duration_ticks, note);
+    }
+    end_of_track(&mut track);
+    file(track)
+}
+
+/// Builds an SMF where every note sounds at once for `duration_ticks`, as
+/// in a chord voicing.
+pub fn chord(notes: &[u8], duration_ticks: u32) -> Vec<u8> {
+    let mut track = Vec::new();
+    tempo_event(&mut track);
+    for &note in notes {
+        note_on(&mut track, 0, note);
+    }
+    for (i, &note) in notes.iter().enumerate() {
+        note_off(&mut track, if i == 0 { duration_ticks } else { 0 }, note);
+    }
+    end_of_track(&mut track);
+    file(track)
+}
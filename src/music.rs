@@ -55,6 +55,13 @@ impl ScaleType {
         }
     }
 
+    fn intervals(&self) -> Vec<i8> {
+        let pattern = self.interval_pattern();
+        (0..pattern.len() - 1)
+            .map(|j| pattern[..j + 1].iter().sum())
+            .collect()
+    }
+
     fn from(s: &str) -> ScaleType {
         match &s.to_lowercase()[..] {
             "major" => ScaleType::Major,
@@ -157,23 +164,225 @@ fn pitch_class_to_letter(pc: i8, bias: Bias) -> char {
     }
 }
 
-struct Chord {
+#[derive(Debug, EnumIter, AsRefStr, PartialEq, Clone, Copy)]
+pub enum ChordType {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    Major7,
+    Minor7,
+    Dominant7,
+    HalfDiminished7,
+}
+
+impl ChordType {
+    fn intervals(&self) -> &[i8] {
+        match self {
+            &Self::Major => &[0, 4, 7],
+            &Self::Minor => &[0, 3, 7],
+            &Self::Diminished => &[0, 3, 6],
+            &Self::Augmented => &[0, 4, 8],
+            &Self::Major7 => &[0, 4, 7, 11],
+            &Self::Minor7 => &[0, 3, 7, 10],
+            &Self::Dominant7 => &[0, 4, 7, 10],
+            &Self::HalfDiminished7 => &[0, 3, 6, 10],
+        }
+    }
+
+    fn from(s: &str) -> ChordType {
+        match &s.to_lowercase()[..] {
+            "major" => ChordType::Major,
+            "minor" => ChordType::Minor,
+            "diminished" => ChordType::Diminished,
+            "augmented" => ChordType::Augmented,
+            "major7" => ChordType::Major7,
+            "minor7" => ChordType::Minor7,
+            "dominant7" => ChordType::Dominant7,
+            "halfdiminished7" => ChordType::HalfDiminished7,
+            _ => ChordType::Major, // todo
+        }
+    }
+
+    pub fn all() -> [ChordType; 8] {
+        [
+            ChordType::Major,
+            ChordType::Minor,
+            ChordType::Diminished,
+            ChordType::Augmented,
+            ChordType::Major7,
+            ChordType::Minor7,
+            ChordType::Dominant7,
+            ChordType::HalfDiminished7,
+        ]
+    }
+}
+
+impl Distribution<ChordType> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ChordType {
+        match rng.gen_range(0..8) {
+            0 => ChordType::Major,
+            1 => ChordType::Minor,
+            2 => ChordType::Diminished,
+            3 => ChordType::Augmented,
+            4 => ChordType::Major7,
+            5 => ChordType::Minor7,
+            6 => ChordType::Dominant7,
+            7 => ChordType::HalfDiminished7,
+            _ => ChordType::Major, // can't happen
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Chord {
     notes: Vec<Note>,
-    name: String,
+    chord_type: ChordType,
 }
 
 impl Chord {
-    fn parse(_s: &str) -> Chord {
-        Chord {
-            notes: Vec::new(),
-            name: String::from("foobar"),
+    pub fn new(root: &Note, chord_type: ChordType) -> Chord {
+        let letters = base(root.spelling);
+        let notes = chord_type
+            .intervals()
+            .iter()
+            .enumerate()
+            .map(|(i, half_steps)| root.add(*half_steps, letters[(i * 2) % letters.len()]))
+            .collect();
+        Chord { notes, chord_type }
+    }
+
+    pub fn string(&self) -> String {
+        let strings: Vec<String> = self.notes.iter().map(|x| x.string()).collect();
+        strings.join(" ")
+    }
+
+    pub fn chord_type(&self) -> ChordType {
+        self.chord_type
+    }
+
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    pub fn play(&self) {
+        let frequencies: Vec<f64> = self.notes.iter().map(|n| n.pitch(4).frequency()).collect();
+        crate::audio::play_chord(&frequencies);
+    }
+
+    /// Renders this chord as a single-track Standard MIDI File with every
+    /// note sounding at once.
+    pub fn to_midi(&self) -> Vec<u8> {
+        let notes: Vec<u8> = self.notes.iter().map(|n| n.pitch(4).midi_number()).collect();
+        crate::midi::chord(&notes, 1920)
+    }
+}
+
+fn classify_chord(notes: &[Note]) -> Option<ChordType> {
+    let root_pc = notes[0].pitch_class;
+    let mut intervals: Vec<i8> = notes
+        .iter()
+        .map(|n| ((n.pitch_class - root_pc) % 12 + 12) % 12)
+        .collect();
+    intervals.sort();
+    intervals.dedup();
+    ChordType::all()
+        .iter()
+        .find(|ct| ct.intervals() == &intervals[..])
+        .copied()
+}
+
+/// Names the interval (in semitones from a root) that a non-root chord
+/// tone represents, e.g. "6th" for 8 or 9 semitones. Used to label an
+/// `Analysis` whose root isn't the bass (the first note given),
+/// so a match reads as an inversion rather than a root-position chord.
+fn ordinal(semitones: i8) -> &'static str {
+    match ((semitones % 12) + 12) % 12 {
+        1 | 2 => "2nd",
+        3 | 4 => "3rd",
+        5 => "4th",
+        6 => "tritone",
+        7 => "5th",
+        8 | 9 => "6th",
+        10 | 11 => "7th",
+        _ => "root",
+    }
+}
+
+pub struct Analysis {
+    pub root: Note,
+    pub label: String,
+}
+
+/// Finds every chord or scale that the given notes could spell, trying
+/// each distinct pitch class as the root in turn. The first note given is
+/// taken as the bass. Results are ranked so that a match whose root is
+/// the bass (i.e. a root-position chord) sorts first; any other match is an inversion and
+/// its label is annotated with which chord tone the bass note occupies,
+/// e.g. "A Minor7 (as 3rd)" when the bass is the third of the chord.
+pub fn identify(notes: &[Note]) -> Vec<Analysis> {
+    let mut pitch_classes: Vec<i8> = notes.iter().map(|n| n.pitch_class).collect();
+    pitch_classes.sort();
+    pitch_classes.dedup();
+
+    let bass_pc = notes[0].pitch_class;
+
+    let mut matches = Vec::new();
+
+    for &root_pc in pitch_classes.iter() {
+        let root = match notes.iter().find(|n| n.pitch_class == root_pc) {
+            Some(n) => *n,
+            None => continue,
+        };
+
+        let mut intervals: Vec<i8> = pitch_classes
+            .iter()
+            .map(|pc| ((pc - root_pc) % 12 + 12) % 12)
+            .collect();
+        intervals.sort();
+
+        let label_for = |quality: &str| -> String {
+            if root_pc == bass_pc {
+                format!("{} {}", root.string(), quality)
+            } else {
+                let inversion = ordinal(((bass_pc - root_pc) % 12 + 12) % 12);
+                format!("{} {} (as {})", root.string(), quality, inversion)
+            }
+        };
+
+        for chord_type in ChordType::all().iter() {
+            if chord_type.intervals() == &intervals[..] {
+                matches.push(Analysis {
+                    root,
+                    label: label_for(chord_type.as_ref()),
+                });
+            }
+        }
+
+        for scale_type in ScaleType::all().iter() {
+            if scale_type.intervals() == intervals {
+                matches.push(Analysis {
+                    root,
+                    label: label_for(scale_type.as_ref()),
+                });
+            }
         }
     }
+
+    matches.sort_by_key(|m| if m.root.pitch_class == bass_pc { 0 } else { 1 });
+    matches
+}
+
+pub fn identify_string(notes: &[Note]) -> String {
+    let input: Vec<String> = notes.iter().map(|n| n.string()).collect();
+    let matches = identify(notes);
+    let labels: Vec<String> = matches.iter().map(|m| m.label.clone()).collect();
+    format!("{} \u{2192} {}", input.join(" "), labels.join(", "))
 }
 
 pub struct Scale {
     notes: Vec<Note>,
-    scale_type: ScaleType,
+    scale_type: Option<ScaleType>,
 }
 
 impl Clone for Scale {
@@ -189,6 +398,45 @@ impl Clone for Scale {
     }
 }
 
+fn parse_steps(steps: &str) -> Vec<i8> {
+    steps
+        .chars()
+        .map(|c| match c {
+            'W' | 'M' => 2,
+            'H' | 'm' => 1,
+            'A' => 3,
+            _ => 0, // TODO
+        })
+        .collect()
+}
+
+/// Reference semitone offset for each letter step above the tonic: the
+/// W-W-H-W-W-W-H spacing of a major scale (for tonic C, the natural
+/// letters C, D, E, F, G, A, B). The same offsets are used whatever the
+/// tonic, even though the natural letters from another starting letter
+/// are spaced differently.
+const NATURAL_OFFSETS: [i8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Picks how many letters to advance from `prev_index` for a cumulative
+/// semitone offset of `target` from the tonic. Normally advances by one
+/// letter, but skips an extra letter (advances by 2) when that lands
+/// closer to a natural spelling, which is how a scale that omits a scale
+/// degree (e.g. a pentatonic scale) ends up skipping a letter instead of
+/// reusing the previous one as a double sharp or flat.
+fn next_letter_index(prev_index: usize, target: i8) -> usize {
+    let natural_offset =
+        |index: usize| -> i16 { NATURAL_OFFSETS[index % 7] as i16 + 12 * (index / 7) as i16 };
+    let target = target as i16;
+    let advance_by_one = prev_index + 1;
+    let advance_by_two = prev_index + 2;
+    if (natural_offset(advance_by_one) - target).abs() <= (natural_offset(advance_by_two) - target).abs()
+    {
+        advance_by_one
+    } else {
+        advance_by_two
+    }
+}
+
 impl Scale {
     pub fn new(tonic: &Note, scale_type: ScaleType) -> Scale {
         let mut notes = Vec::new();
@@ -197,7 +445,37 @@ impl Scale {
             let degree = tonic.add(half_steps, *letter);
             notes.push(degree)
         }
-        Scale { notes, scale_type }
+        Scale {
+            notes,
+            scale_type: Some(scale_type),
+        }
+    }
+
+    /// Builds a scale from a step string, e.g. "WWHWWWH" (major) or the
+    /// mixed-case "MMmMMMm" convention, where W/M = whole step, H/m = half
+    /// step, and A = augmented second. Supports scales of any length up to
+    /// the 7 letters of the musical alphabet; a step large enough to skip a
+    /// letter entirely (as in a pentatonic scale) advances the letter index
+    /// by 2 instead of 1, so e.g. C major pentatonic spells as "C D E G A"
+    /// rather than reusing F twice over as a double sharp.
+    pub fn from_steps(tonic: &Note, steps: &str) -> Scale {
+        let deltas = parse_steps(steps);
+        let letters = base(tonic.spelling);
+
+        let mut notes = vec![*tonic];
+        let mut half_steps: i8 = 0;
+        let mut letter_index = 0;
+        for delta in deltas[..deltas.len().saturating_sub(1)].iter() {
+            half_steps += delta;
+            letter_index = next_letter_index(letter_index, half_steps);
+            let letter = letters[letter_index % letters.len()];
+            notes.push(tonic.add(half_steps, letter));
+        }
+
+        Scale {
+            notes,
+            scale_type: None,
+        }
     }
 
     pub fn string(&self) -> String {
@@ -205,7 +483,7 @@ impl Scale {
         strings.join(" ")
     }
 
-    pub fn scale_type(&self) -> ScaleType {
+    pub fn scale_type(&self) -> Option<ScaleType> {
         self.scale_type
     }
 
@@ -219,6 +497,80 @@ impl Scale {
     fn tonic(&self) -> &Note {
         self.notes.get(0).unwrap()
     }
+
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    pub fn play(&self) {
+        let frequencies: Vec<f64> = self.notes.iter().map(|n| n.pitch(4).frequency()).collect();
+        crate::audio::play_sequence(&frequencies);
+    }
+
+    /// Renders this scale as a single-track Standard MIDI File, one note
+    /// after another.
+    pub fn to_midi(&self) -> Vec<u8> {
+        let notes: Vec<u8> = self.notes.iter().map(|n| n.pitch(4).midi_number()).collect();
+        crate::midi::sequence(&notes, 480)
+    }
+
+    pub fn roman_numerals(&self) -> Vec<String> {
+        let tonic = *self.tonic();
+        self.notes
+            .iter()
+            .map(|n| n.string_in(Notation::Roman, Some(&tonic)))
+            .collect()
+    }
+
+    /// Builds the chord stacked on each scale degree by taking every other
+    /// scale note (thirds), wrapping around the scale when a stack runs past
+    /// the top. `size` is 3 for triads, 4 for sevenths.
+    pub fn diatonic_chords(&self, size: usize) -> Vec<DiatonicChord> {
+        let len = self.notes.len();
+        (0..len)
+            .map(|degree| {
+                let notes: Vec<Note> = (0..size)
+                    .map(|k| self.notes[(degree + 2 * k) % len])
+                    .collect();
+                let chord_type = classify_chord(&notes);
+                DiatonicChord {
+                    degree,
+                    root: notes[0],
+                    notes,
+                    chord_type,
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct DiatonicChord {
+    pub degree: usize,
+    pub root: Note,
+    pub notes: Vec<Note>,
+    pub chord_type: Option<ChordType>,
+}
+
+impl DiatonicChord {
+    pub fn string(&self) -> String {
+        let strings: Vec<String> = self.notes.iter().map(|x| x.string()).collect();
+        strings.join(" ")
+    }
+
+    /// A Roman numeral label with case and a quality glyph matching the
+    /// chord built on this degree, e.g. "I", "ii", "vii°".
+    pub fn roman_label(&self, tonic: &Note) -> String {
+        let numeral = self.root.string_in(Notation::Roman, Some(tonic));
+        match self.chord_type {
+            Some(ChordType::Major) | Some(ChordType::Major7) | Some(ChordType::Dominant7) => numeral,
+            Some(ChordType::Minor) | Some(ChordType::Minor7) => numeral.to_lowercase(),
+            Some(ChordType::Diminished) | Some(ChordType::HalfDiminished7) => {
+                format!("{}\u{b0}", numeral.to_lowercase())
+            }
+            Some(ChordType::Augmented) => format!("{}+", numeral),
+            None => format!("{}?", numeral),
+        }
+    }
 }
 
 struct Pitch {
@@ -227,6 +579,13 @@ struct Pitch {
 }
 
 impl Pitch {
+    fn new(pitch_class: i8, octave: i8) -> Pitch {
+        Pitch {
+            pitch_class: pitch_class % 12,
+            octave,
+        }
+    }
+
     fn parse(s: String) -> Pitch {
         let re = Regex::new(r"([ABCDEFG])([#♯𝄪b♭𝄫]*)([0-9]+)").unwrap();
         let mut pc: i8 = 0;
@@ -253,7 +612,12 @@ impl Pitch {
         }
     }
     fn frequency(&self) -> f64 {
-        return 0.0;
+        let midi_number = (self.octave + 1) as f64 * 12.0 + self.pitch_class as f64;
+        440.0 * 2f64.powf((midi_number - 69.0) / 12.0)
+    }
+
+    fn midi_number(&self) -> u8 {
+        ((self.octave + 1) as i16 * 12 + self.pitch_class as i16) as u8
     }
 }
 
@@ -266,6 +630,14 @@ pub fn circle_of_fifths() -> Vec<Note> {
     .collect()
 }
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Notation {
+    English,
+    German,
+    Nashville,
+    Roman,
+}
+
 #[derive(Clone, Copy)]
 pub struct Note {
     spelling: char,
@@ -273,7 +645,7 @@ pub struct Note {
 }
 
 impl Note {
-    fn string(&self) -> String {
+    pub fn string(&self) -> String {
         match self.adjustment() {
             2 => format!("{}𝄪", self.spelling),
             1 => format!("{}♯", self.spelling),
@@ -284,6 +656,68 @@ impl Note {
         }
     }
 
+    pub fn string_in(&self, notation: Notation, key: Option<&Note>) -> String {
+        match notation {
+            Notation::English => self.string(),
+            Notation::German => self.string_german(),
+            Notation::Nashville => match key {
+                Some(k) => self.string_nashville(k),
+                None => self.string(),
+            },
+            Notation::Roman => match key {
+                Some(k) => self.string_roman(k),
+                None => self.string(),
+            },
+        }
+    }
+
+    fn string_german(&self) -> String {
+        match (self.spelling, self.adjustment()) {
+            ('B', 0) => String::from("H"),
+            ('B', -1) => String::from("B"),
+            _ => self.string(),
+        }
+    }
+
+    fn degree_label(&self, key: &Note) -> (i8, i8) {
+        let semitones = ((self.pitch_class - key.pitch_class) % 12 + 12) % 12;
+        match semitones {
+            0 => (1, 0),
+            1 => (2, -1),
+            2 => (2, 0),
+            3 => (3, -1),
+            4 => (3, 0),
+            5 => (4, 0),
+            6 => (4, 1),
+            7 => (5, 0),
+            8 => (6, -1),
+            9 => (6, 0),
+            10 => (7, -1),
+            11 => (7, 0),
+            _ => (1, 0), // can't happen
+        }
+    }
+
+    fn string_nashville(&self, key: &Note) -> String {
+        let (degree, accidental) = self.degree_label(key);
+        match accidental {
+            1 => format!("♯{}", degree),
+            -1 => format!("♭{}", degree),
+            _ => format!("{}", degree),
+        }
+    }
+
+    fn string_roman(&self, key: &Note) -> String {
+        let numerals = ["I", "II", "III", "IV", "V", "VI", "VII"];
+        let (degree, accidental) = self.degree_label(key);
+        let numeral = numerals[(degree - 1) as usize];
+        match accidental {
+            1 => format!("♯{}", numeral),
+            -1 => format!("♭{}", numeral),
+            _ => String::from(numeral),
+        }
+    }
+
     fn clone(&self) -> Note {
         Note {
             spelling: self.spelling,
@@ -324,6 +758,10 @@ impl Note {
         }
     }
 
+    fn pitch(&self, octave: i8) -> Pitch {
+        Pitch::new(self.pitch_class, octave)
+    }
+
     fn new(spelling: char, pitch_class: i8) -> Note {
         Note {
             spelling,
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use std::fs;
+
+const STATS_PATH: &str = "musicquiz_stats.json";
+
+/// Per-question record used to weight spaced-repetition sampling. `weight`
+/// starts at 1.0, grows on a wrong answer, and decays (floor 0.2) on a
+/// correct one, so recently-missed items keep surfacing more often.
+struct ItemStats {
+    correct: u32,
+    incorrect: u32,
+    weight: f64,
+}
+
+impl Default for ItemStats {
+    fn default() -> ItemStats {
+        ItemStats {
+            correct: 0,
+            incorrect: 0,
+            weight: 1.0,
+        }
+    }
+}
+
+pub struct Stats {
+    items: HashMap<String, ItemStats>,
+    score: u32,
+    streak: u32,
+    best_streak: u32,
+}
+
+impl Default for Stats {
+    fn default() -> Stats {
+        Stats {
+            items: HashMap::new(),
+            score: 0,
+            streak: 0,
+            best_streak: 0,
+        }
+    }
+}
+
+/// A hand-rolled JSON value, just expressive enough to round-trip the
+/// shape `serialize()` produces: an object of numbers/strings/arrays.
+enum Json {
+    Object(Vec<(String, Json)>),
+    Array(Vec<Json>),
+    String(String),
+    Number(f64),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn as_u32(&self) -> Option<u32> {
+        self.as_f64().map(|n| n as u32)
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Parses just enough JSON to read back what `serialize()` wrote. Unlike
+/// scanning for field-name substrings, this tokenizes quoted strings
+/// properly, so a key isn't confused with a reserved field name that
+/// happens to appear inside it.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Parser<'a> {
+        Parser {
+            chars: s.chars().peekable(),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.chars.peek(), Some(c) if c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<Json> {
+        self.skip_whitespace();
+        match self.chars.peek()? {
+            '{' => self.parse_object(),
+            '[' => self.parse_array(),
+            '"' => self.parse_string().map(Json::String),
+            _ => self.parse_number(),
+        }
+    }
+
+    fn parse_object(&mut self) -> Option<Json> {
+        self.chars.next(); // consume '{'
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.chars.next();
+            return Some(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if self.chars.next()? != ':' {
+                return None;
+            }
+            let value = self.parse_value()?;
+            fields.push((key, value));
+            self.skip_whitespace();
+            match self.chars.next()? {
+                ',' => continue,
+                '}' => break,
+                _ => return None,
+            }
+        }
+        Some(Json::Object(fields))
+    }
+
+    fn parse_array(&mut self) -> Option<Json> {
+        self.chars.next(); // consume '['
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.chars.next();
+            return Some(Json::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.chars.next()? {
+                ',' => continue,
+                ']' => break,
+                _ => return None,
+            }
+        }
+        Some(Json::Array(items))
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        if self.chars.next()? != '"' {
+            return None;
+        }
+        let mut s = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => break,
+                '\\' => s.push(self.chars.next()?),
+                c => s.push(c),
+            }
+        }
+        Some(s)
+    }
+
+    fn parse_number(&mut self) -> Option<Json> {
+        let mut s = String::new();
+        while matches!(self.chars.peek(), Some(c) if c.is_ascii_digit() || *c == '.' || *c == '-') {
+            s.push(self.chars.next().unwrap());
+        }
+        s.parse().ok().map(Json::Number)
+    }
+}
+
+/// Escapes `"` and `\` so a key can round-trip through a JSON string
+/// literal even if it contains one of those characters.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Stats {
+    /// Loads totals from `musicquiz_stats.json` in the working directory,
+    /// falling back to a fresh `Stats` if the file is missing or unreadable.
+    pub fn load() -> Stats {
+        match fs::read_to_string(STATS_PATH) {
+            Ok(contents) => Stats::parse(&contents),
+            Err(_) => Stats::default(),
+        }
+    }
+
+    /// Persists totals back to `musicquiz_stats.json`. Failures are ignored
+    /// so a read-only working directory doesn't interrupt the quiz.
+    pub fn save(&self) {
+        let _ = fs::write(STATS_PATH, self.serialize());
+    }
+
+    fn serialize(&self) -> String {
+        let mut keys: Vec<&String> = self.items.keys().collect();
+        keys.sort();
+        let entries: Vec<String> = keys
+            .iter()
+            .map(|key| {
+                let item = &self.items[*key];
+                format!(
+                    "{{\"key\":\"{}\",\"correct\":{},\"incorrect\":{},\"weight\":{}}}",
+                    escape(key),
+                    item.correct,
+                    item.incorrect,
+                    item.weight
+                )
+            })
+            .collect();
+        format!(
+            "{{\"score\":{},\"streak\":{},\"best_streak\":{},\"items\":[{}]}}",
+            self.score,
+            self.streak,
+            self.best_streak,
+            entries.join(",")
+        )
+    }
+
+    fn parse(contents: &str) -> Stats {
+        let root = Parser::new(contents).parse_value();
+
+        let mut items = HashMap::new();
+        if let Some(entries) = root.as_ref().and_then(|v| v.get("items")).and_then(Json::as_array) {
+            for entry in entries {
+                let key = match entry.get("key").and_then(Json::as_str) {
+                    Some(k) => k.to_string(),
+                    None => continue,
+                };
+                items.insert(
+                    key,
+                    ItemStats {
+                        correct: entry.get("correct").and_then(Json::as_u32).unwrap_or(0),
+                        incorrect: entry.get("incorrect").and_then(Json::as_u32).unwrap_or(0),
+                        weight: entry.get("weight").and_then(Json::as_f64).unwrap_or(1.0),
+                    },
+                );
+            }
+        }
+
+        Stats {
+            items,
+            score: root.as_ref().and_then(|v| v.get("score")).and_then(Json::as_u32).unwrap_or(0),
+            streak: root.as_ref().and_then(|v| v.get("streak")).and_then(Json::as_u32).unwrap_or(0),
+            best_streak: root
+                .as_ref()
+                .and_then(|v| v.get("best_streak"))
+                .and_then(Json::as_u32)
+                .unwrap_or(0),
+        }
+    }
+
+    /// The current sampling weight for `key`, defaulting to 1.0 for an item
+    /// that hasn't been seen yet.
+    pub fn weight(&self, key: &str) -> f64 {
+        self.items.get(key).map(|i| i.weight).unwrap_or(1.0)
+    }
+
+    pub fn record_correct(&mut self, key: &str) {
+        self.score += 1;
+        self.streak += 1;
+        if self.streak > self.best_streak {
+            self.best_streak = self.streak;
+        }
+        let item = self.items.entry(key.to_string()).or_default();
+        item.correct += 1;
+        item.weight = (item.weight * 0.5).max(0.2);
+    }
+
+    pub fn record_incorrect(&mut self, key: &str) {
+        self.streak = 0;
+        let item = self.items.entry(key.to_string()).or_default();
+        item.incorrect += 1;
+        item.weight += 1.0;
+    }
+
+    /// A single-line summary suitable for a footer, e.g. "Score: 12  Streak: 3 (best 7)".
+    pub fn footer(&self) -> String {
+        format!(
+            "Score: {}  Streak: {} (best {})",
+            self.score, self.streak, self.best_streak
+        )
+    }
+}
+
+/// Picks an index into `weights` with probability proportional to its
+/// weight, falling back to a uniform pick if the weights sum to zero.
+pub fn weighted_index(rng: &mut impl rand::Rng, weights: &[f64]) -> usize {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return rng.gen_range(0..weights.len());
+    }
+    let mut pick = rng.gen::<f64>() * total;
+    for (i, w) in weights.iter().enumerate() {
+        if pick < *w {
+            return i;
+        }
+        pick -= *w;
+    }
+    weights.len() - 1
+}